@@ -25,10 +25,11 @@ use frame_system::{ensure_root, ensure_signed};
 use frame_support::pallet_prelude::EnsureOrigin;
 use sp_arithmetic::per_things::Rounding;
 use sp_consensus_poscan::POSCAN_ENGINE_ID;
-use sp_runtime::traits::{Saturating, Zero};
+use sp_runtime::traits::{Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero};
 use sp_runtime::{Perbill, Percent};
 use sp_std::{
-	collections::btree_map::BTreeMap, iter::FromIterator, ops::Bound::Included, prelude::*,
+	collections::btree_map::BTreeMap,
+	iter::FromIterator, ops::Bound::Included, prelude::*,
 };
 use sp_std::convert::TryInto;
 use scale_info::TypeInfo;
@@ -40,6 +41,10 @@ use mining_pool_stat_api::MiningPoolStatApi;
 use sp_consensus_poscan::Difficulty;
 pub const LOG_TARGET: &'static str = "runtime::validator-set";
 
+/// Fixed-point scale used for the `RewardPerWeightStored` accumulator, modelled on the
+/// reward-per-token scheme of orml-rewards / bifrost's ve-minting incentive module.
+const REWARD_PER_WEIGHT_SCALE: u128 = 1_000_000_000_000_000_000u128;
+
 
 pub struct LockBounds {
 	pub period_max: u16,
@@ -54,6 +59,43 @@ pub struct LockParameters {
 	pub divide: u16,
 }
 
+/// A predictable disinflation curve, computed analytically instead of via pre-computed
+/// `RewardChanges` points. Modelled on Centrifuge's `treasury_inflation_rate` + `total_reward`
+/// and bifrost liquidity-mining's `block_startup`/`duration`/`block_retired`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EmissionSchedule<BlockNumber, Balance> {
+	/// Block at which emission starts.
+	pub start: BlockNumber,
+	/// Total amount to be emitted over `duration`.
+	pub total_budget: Balance,
+	/// Number of blocks the schedule runs for.
+	pub duration: BlockNumber,
+	/// When set, the base reward (`total_budget / duration`) is halved every
+	/// `halving_interval` blocks instead of staying linear.
+	pub halving_interval: Option<BlockNumber>,
+}
+
+/// Kind of miner misbehaviour a `report_miner` call can report, modelled on CESS's
+/// `clear_punish` / `force_miner_exit` flow.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum MinerMisbehaviorKind {
+	/// The miner produced an invalid block.
+	InvalidBlock,
+	/// The miner produced a duplicate block.
+	DuplicateBlock,
+	/// The miner failed a storage/availability challenge.
+	FailedChallenge,
+}
+
+/// Force a misbehaving miner out of the validator set.
+///
+/// This lives here rather than on `validator_set_api::ValidatorSetApi` because the
+/// `validator-set-api` crate isn't part of this pallet; `T::ValidatorSet` implementations are
+/// expected to implement both traits.
+pub trait ForceExitApi<AccountId> {
+	fn force_exit(account: &AccountId);
+}
+
 /// Trait for generating reward locks.
 pub trait GenerateRewardLocks<T: Config> {
 	/// Generate reward locks.
@@ -75,6 +117,11 @@ pub trait WeightInfo {
 	fn set_schedule() -> Weight;
 	fn set_lock_params() -> Weight;
 	fn set_miner_share() -> Weight;
+	fn claim_pool_rewards() -> Weight;
+	fn set_emission_schedule() -> Weight;
+	fn clear_emission_schedule() -> Weight;
+	fn report_miner() -> Weight;
+	fn clear_punish() -> Weight;
 }
 
 /// Config for rewards.
@@ -92,7 +139,8 @@ pub trait Config: frame_system::Config + pallet_treasury::Config + pallet_balanc
 	/// Lock Parameters Bounds.
 	type LockParametersBounds: Get<LockBounds>;
 	/// Pallet validator
-	type ValidatorSet: ValidatorSetApi<Self::AccountId, Self::BlockNumber, BalanceOf::<Self>>;
+	type ValidatorSet: ValidatorSetApi<Self::AccountId, Self::BlockNumber, BalanceOf::<Self>>
+		+ ForceExitApi<Self::AccountId>;
 	/// Percent of rewards for miner
 	type MinerRewardsPercent: Get<Percent>;
 	/// Percent of rewards for miner
@@ -101,6 +149,16 @@ pub trait Config: frame_system::Config + pallet_treasury::Config + pallet_balanc
 	type MiningPoolMaxRate: Get<Percent>;
 	/// Miner share origin
 	type MinerShareOrigin: EnsureOrigin<Self::Origin>;
+	/// Origin allowed to report miner misbehaviour via `report_miner`.
+	type MinerReportOrigin: EnsureOrigin<Self::Origin>;
+	/// Fraction of a miner's remaining locked rewards slashed per strike: strike `n` slashes
+	/// `min(100%, n * StrikeSlashStep)`.
+	type StrikeSlashStep: Get<Percent>;
+	/// Number of strikes after which a miner is forced out of the validator set.
+	type MaxStrikes: Get<u32>;
+	/// Minimum lock amount worth tracking. Generated locks (and summed existing locks) below
+	/// this are left spendable instead of bloating `RewardLocks` with dust entries.
+	type MinLockAmount: Get<BalanceOf<Self>>;
 }
 
 /// Type alias for currency balance.
@@ -123,6 +181,8 @@ decl_error! {
 		UnsufficientBalance,
 		/// decrease lock amount not allowed .
 		DecreaseLockAmountNotAllowed,
+		/// Emission schedule duration must be greater than zero.
+		EmissionDurationTooLow,
 	}
 }
 
@@ -149,7 +209,13 @@ decl_module! {
 			}
 
 			let cur_block_number = <frame_system::Pallet<T>>::block_number();
-			let cur_reward = T::GenerateRewardLocks::calc_rewards(cur_block_number);
+			let cur_reward = match <Self as Store>::ActiveEmissionSchedule::get() {
+				// A schedule that hasn't started yet shouldn't freeze emissions in the
+				// meantime; fall back to the usual calc_rewards curve until `start`.
+				Some(schedule) if cur_block_number >= schedule.start =>
+					Self::emission_reward(cur_block_number, &schedule),
+				_ => T::GenerateRewardLocks::calc_rewards(cur_block_number),
+			};
 			let d = u128::from_le_bytes(cur_reward.encode().try_into().unwrap());
 
 			log::debug!(target: LOG_TARGET, "cur_reward: {}", d);
@@ -249,6 +315,38 @@ decl_module! {
 			Self::deposit_event(RawEvent::ScheduleSet);
 		}
 
+		/// Set an analytical emission curve, used as an alternative to maintaining
+		/// `RewardChanges` points by hand. While active, the block reward is derived from
+		/// this schedule; `RewardChanges` still overrides it for any block explicitly listed
+		/// there.
+		#[weight = <T as Config>::WeightInfo::set_emission_schedule()]
+		fn set_emission_schedule(
+			origin,
+			start: T::BlockNumber,
+			total_budget: BalanceOf<T>,
+			duration: T::BlockNumber,
+			halving_interval: Option<T::BlockNumber>,
+		) {
+			ensure_root(origin)?;
+
+			ensure!(!duration.is_zero(), Error::<T>::EmissionDurationTooLow);
+			ensure!(total_budget >= <T as Config>::Currency::minimum_balance(), Error::<T>::RewardTooLow);
+
+			let schedule = EmissionSchedule { start, total_budget, duration, halving_interval };
+			<Self as Store>::ActiveEmissionSchedule::put(schedule.clone());
+			Self::deposit_event(RawEvent::EmissionScheduleSet(schedule));
+		}
+
+		/// Clear the active emission schedule, reverting the block reward to
+		/// `GenerateRewardLocks::calc_rewards` / `RewardChanges`.
+		#[weight = <T as Config>::WeightInfo::clear_emission_schedule()]
+		fn clear_emission_schedule(origin) {
+			ensure_root(origin)?;
+
+			<Self as Store>::ActiveEmissionSchedule::kill();
+			Self::deposit_event(RawEvent::EmissionScheduleCleared);
+		}
+
 		#[weight = <T as Config>::WeightInfo::set_lock_params()]
 		fn set_lock_params(origin, lock_params: LockParameters) {
 			ensure_root(origin)?;
@@ -280,7 +378,42 @@ decl_module! {
 
 			let locks = Self::reward_locks(&target);
 			let current_number = frame_system::Pallet::<T>::block_number();
-			Self::do_update_reward_locks(&target, locks, current_number, false);
+			Self::do_update_reward_locks(&target, locks, current_number, false, true);
+		}
+
+		/// Settle and pay out a pool member's lazily-accrued rewards for mining `pool`.
+		///
+		/// Takes `pool` in addition to `origin`: a member can be party to more than one pool,
+		/// and there's no other way to say which accrual to settle, so this deviates from
+		/// the single-argument `claim_pool_rewards(origin)` signature originally proposed.
+		#[weight = <T as Config>::WeightInfo::claim_pool_rewards()]
+		fn claim_pool_rewards(origin, pool: T::AccountId) {
+			let who = ensure_signed(origin)?;
+
+			let pool_stat = T::MiningPool::get_stat(&pool);
+
+			// A member who has since left the pool (or whose pool dissolved) still has a
+			// claim on whatever was already accrued into `MemberRewards`; settling with
+			// weight 0 pays that out without granting them any further share.
+			let weight = pool_stat.as_ref()
+				.and_then(|pool_stat| Self::normalized_member_weight(pool_stat, &who))
+				.unwrap_or(0);
+
+			// A `pool` that doesn't exist and was never settled for `who` before has
+			// nothing accrued to pay out; settling it anyway would only plant a zero-value
+			// entry in `MemberRewardPerWeightPaid`/`MemberRewards` for a fabricated address.
+			let has_prior_state = <Self as Store>::MemberRewardPerWeightPaid::contains_key((&pool, &who))
+				|| <Self as Store>::MemberRewards::contains_key((&pool, &who));
+			if pool_stat.is_some() || has_prior_state {
+				Self::settle_member(&pool, &who, weight);
+			}
+
+			let earned = <Self as Store>::MemberRewards::take((&pool, &who));
+			if !earned.is_zero() {
+				let current_number = frame_system::Pallet::<T>::block_number();
+				Self::do_reward_per_account(&who, earned, current_number);
+				Self::deposit_event(Event::<T>::Rewarded(who, earned));
+			}
 		}
 
 		#[weight = 0]
@@ -292,7 +425,46 @@ decl_module! {
 
 			let locks = Self::reward_locks(&account_id);
 			let current_number = frame_system::Pallet::<T>::block_number();
-			Self::do_update_reward_locks(&account_id, locks, current_number, true);
+			Self::do_update_reward_locks(&account_id, locks, current_number, true, true);
+		}
+
+		/// Report `miner` for misbehaviour, adding a strike and slashing an escalating
+		/// fraction of their remaining locked rewards. Forces the miner out of the
+		/// validator set once `MaxStrikes` is reached.
+		#[weight = <T as Config>::WeightInfo::report_miner()]
+		fn report_miner(origin, miner: T::AccountId, kind: MinerMisbehaviorKind) {
+			T::MinerReportOrigin::ensure_origin(origin)?;
+
+			let strikes = <Self as Store>::MinerStrikes::mutate(&miner, |n| {
+				*n = n.saturating_add(1);
+				*n
+			});
+			log::debug!(target: LOG_TARGET, "miner {:?} struck ({:?}), now at {} strike(s)", miner.encode(), kind, strikes);
+
+			let step = T::StrikeSlashStep::get().deconstruct() as u32;
+			let pct = step.saturating_mul(strikes).min(100) as u8;
+			let fraction = Percent::from_parts(pct);
+
+			let current_number = frame_system::Pallet::<T>::block_number();
+			let slashed = Self::slash_miner_locks(&miner, fraction, current_number);
+			if !slashed.is_zero() {
+				Self::deposit_event(Event::<T>::MinerSlash(miner.clone(), slashed));
+			}
+
+			if strikes >= T::MaxStrikes::get() {
+				T::ValidatorSet::force_exit(&miner);
+				<Self as Store>::MinerStrikes::remove(&miner);
+				Self::deposit_event(Event::<T>::MinerForcedExit(miner));
+			}
+		}
+
+		/// Zero out a miner's strike counter, mirroring CESS's clear-on-good-behaviour semantics.
+		#[weight = <T as Config>::WeightInfo::clear_punish()]
+		fn clear_punish(origin, miner: T::AccountId) {
+			ensure_root(origin)?;
+
+			<Self as Store>::MinerStrikes::remove(&miner);
+			Self::deposit_event(Event::<T>::MinerStrikesCleared(miner));
 		}
 	}
 }
@@ -320,12 +492,41 @@ decl_storage! {
 		/// Miner share percent.
 		MinerShare get(fn miner_percent): Option<Percent>;
 
+		/// Running total of generated lock amounts skipped as dust for an account whose locked
+		/// total stays below `MinLockAmount`. Once it tips a payout over the threshold, that
+		/// payout's lock entries are inserted and the counter is cleared; the skipped amounts
+		/// themselves are never locked, and `do_update_reward_locks` still drops the whole lock
+		/// if the non-expired locked total is below `MinLockAmount`.
+		UnlockedDust get(fn unlocked_dust): map hasher(twox_64_concat) T::AccountId => BalanceOf<T>;
+
+		/// Accumulated reward-per-weight for a mining pool, scaled by `REWARD_PER_WEIGHT_SCALE`.
+		/// Bumped once per block reward instead of writing every member's balance.
+		RewardPerWeightStored get(fn reward_per_weight_stored):
+			map hasher(twox_64_concat) T::AccountId => BalanceOf<T>;
+		/// Snapshot of `RewardPerWeightStored` last seen by a `(pool, member)` pair.
+		MemberRewardPerWeightPaid get(fn member_reward_per_weight_paid):
+			map hasher(twox_64_concat) (T::AccountId, T::AccountId) => BalanceOf<T>;
+		/// Rewards accrued for a `(pool, member)` pair that have not yet been claimed.
+		MemberRewards get(fn member_rewards):
+			map hasher(twox_64_concat) (T::AccountId, T::AccountId) => BalanceOf<T>;
+		/// Active analytical emission curve, if any. Overrides `GenerateRewardLocks::calc_rewards`
+		/// as the source of the block reward from its `start` block on, while set.
+		ActiveEmissionSchedule get(fn active_emission_schedule):
+			Option<EmissionSchedule<T::BlockNumber, BalanceOf<T>>>;
+
+		/// Outstanding misbehaviour strikes per miner.
+		MinerStrikes get(fn miner_strikes): map hasher(twox_64_concat) T::AccountId => u32;
+
 		StorageVersion build(|_| migrations::StorageVersion::V1): migrations::StorageVersion;
 	}
 }
 
 decl_event! {
-	pub enum Event<T> where AccountId = <T as frame_system::Config>::AccountId, Balance = BalanceOf<T> {
+	pub enum Event<T> where
+		AccountId = <T as frame_system::Config>::AccountId,
+		Balance = BalanceOf<T>,
+		BlockNumber = <T as frame_system::Config>::BlockNumber,
+	{
 		/// A new schedule has been set.
 		ScheduleSet,
 		/// Reward has been sent.
@@ -346,12 +547,60 @@ decl_event! {
 		MinerShare(Percent),
 		/// Miner slashed.
 		PoolExceedsLimit(AccountId, Balance),
+		/// An emission schedule has been set.
+		EmissionScheduleSet(EmissionSchedule<BlockNumber, Balance>),
+		/// The active emission schedule has been cleared.
+		EmissionScheduleCleared,
+		/// A miner has been forced out of the validator set for repeated misbehaviour.
+		MinerForcedExit(AccountId),
+		/// A miner's strike counter has been cleared.
+		MinerStrikesCleared(AccountId),
 	}
 }
 // Must be the same as in validator-set pallet
 const REWARDS_ID: LockIdentifier = *b"rewards ";
 
 impl<T: Config> Module<T> {
+	/// Compute the block reward for `now` from an active `EmissionSchedule`, without needing
+	/// pre-computed `RewardChanges` points. Linear mode divides `total_budget` evenly over
+	/// `duration`; halving mode additionally right-shifts that base reward once per elapsed
+	/// `halving_interval`. Saturates to zero once `now >= start + duration`.
+	fn emission_reward(
+		now: T::BlockNumber,
+		schedule: &EmissionSchedule<T::BlockNumber, BalanceOf<T>>,
+	) -> BalanceOf<T> {
+		if now < schedule.start || schedule.duration.is_zero() {
+			return Zero::zero();
+		}
+
+		let elapsed = now.saturating_sub(schedule.start);
+		if elapsed >= schedule.duration {
+			return Zero::zero();
+		}
+
+		let duration: u128 = schedule.duration.unique_saturated_into();
+		let total_budget: u128 = schedule.total_budget.unique_saturated_into();
+		let base_reward = total_budget / duration;
+
+		let reward = match schedule.halving_interval {
+			None => base_reward,
+			Some(halving_interval) if !halving_interval.is_zero() => {
+				let halving_interval: u128 = halving_interval.unique_saturated_into();
+				let elapsed: u128 = elapsed.unique_saturated_into();
+				let epoch = elapsed / halving_interval;
+
+				if epoch >= u128::BITS as u128 {
+					0
+				} else {
+					base_reward.checked_shr(epoch as u32).unwrap_or(0)
+				}
+			}
+			Some(_) => base_reward,
+		};
+
+		BalanceOf::<T>::unique_saturated_from(reward)
+	}
+
 	fn do_reward(author: &T::AccountId, reward: BalanceOf<T>, when: T::BlockNumber) {
 		let miner_share = <Self as Store>::MinerShare::get()
 			.unwrap_or_else(|| T::MinerRewardsPercent::get());
@@ -386,18 +635,17 @@ impl<T: Config> Module<T> {
 			log::trace!(target: LOG_TARGET, "pool_total: {:?}", pool_total);
 
 			let members_total = miner_total.saturating_sub(pool_total);
-			let sum_weight = pool_stat.2.iter().map(|a| a.1 as u64).sum();
-			let tot_weight = if sum_weight == 0 { pool_stat.2.iter().count() as u64 } else { sum_weight };
-
-			let mut payed_rewards: BalanceOf<T> = Zero::zero();
-			for (member_id, w) in pool_stat.2.iter() {
-				let w = if sum_weight == 0 { 1 } else { *w };
-				let rewards = Perbill::from_rational(w as u64, tot_weight) * members_total;
-				log::trace!(target: LOG_TARGET, "miner_member_reword: {:?}", rewards);
-				Self::do_reward_per_account(&member_id, rewards, when);
-				payed_rewards = payed_rewards.saturating_add(rewards);
+
+			if pool_stat.2.is_empty() {
+				// No members: route what would have been theirs back to the author.
+				miner_total = pool_total.saturating_add(members_total);
+			} else {
+				let sum_weight: u64 = pool_stat.2.iter().map(|a| a.1 as u64).sum();
+				let tot_weight = if sum_weight == 0 { pool_stat.2.iter().count() as u64 } else { sum_weight };
+
+				Self::bump_reward_per_weight(author, &pool_stat.2, members_total, tot_weight);
+				miner_total = pool_total;
 			}
-			miner_total = pool_total.saturating_add(members_total.saturating_sub(payed_rewards));
 		}
 		Self::do_reward_per_account(author, miner_total, when);
 		let validator_total = reward.saturating_sub(miner_total);
@@ -415,6 +663,85 @@ impl<T: Config> Module<T> {
 		}
 	}
 
+	/// Bump a pool's lazy reward accumulator by `members_total` spread over `tot_weight`,
+	/// a single storage write in place of iterating every pool member.
+	///
+	/// Also snapshots `MemberRewardPerWeightPaid` for any member with no entry yet, to the
+	/// accumulator's value *before* this bump, so a first-time joiner doesn't claim a share
+	/// of rewards accrued before they joined. This `contains_key` check is a fallback for
+	/// members who predate any weight-change event (e.g. present when the pool was first
+	/// observed) — it costs one keyed read per member and writes only on an actual miss, so
+	/// a pool with no new joiners this block writes no per-member storage here at all. A
+	/// member who leaves and later rejoins is re-snapshotted by `on_member_weight_changed`'s
+	/// `settle_member` call on the 0->w transition, not by anything in this function; the
+	/// owning mining-pool pallet is expected to invoke that hook on every join and leave per
+	/// its documented call contract.
+	fn bump_reward_per_weight(
+		pool: &T::AccountId,
+		members: &[(T::AccountId, u32)],
+		members_total: BalanceOf<T>,
+		tot_weight: u64,
+	) {
+		let stored_before = <Self as Store>::RewardPerWeightStored::get(pool);
+		for (member, _) in members {
+			if !<Self as Store>::MemberRewardPerWeightPaid::contains_key((pool, member)) {
+				<Self as Store>::MemberRewardPerWeightPaid::insert((pool, member), stored_before);
+			}
+		}
+
+		if tot_weight == 0 || members_total.is_zero() {
+			return;
+		}
+
+		let members_total: u128 = members_total.unique_saturated_into();
+		let delta = members_total.saturating_mul(REWARD_PER_WEIGHT_SCALE) / tot_weight as u128;
+
+		<Self as Store>::RewardPerWeightStored::mutate(pool, |stored| {
+			let stored_u128: u128 = (*stored).unique_saturated_into();
+			*stored = BalanceOf::<T>::unique_saturated_from(stored_u128.saturating_add(delta));
+		});
+	}
+
+	/// A pool member's weight for reward-accrual purposes: when every member in `pool_stat.2`
+	/// has weight 0 (the "unweighted" pool case `do_reward` also handles), everyone is
+	/// normalized to weight 1 instead of earning nothing. Returns `None` if `member` isn't
+	/// (or is no longer) listed in `pool_stat.2`.
+	fn normalized_member_weight(
+		pool_stat: &(Percent, Percent, Vec<(T::AccountId, u32)>),
+		member: &T::AccountId,
+	) -> Option<u64> {
+		let sum_weight: u64 = pool_stat.2.iter().map(|a| a.1 as u64).sum();
+
+		pool_stat.2.iter()
+			.find(|(m, _)| m == member)
+			.map(|(_, w)| if sum_weight == 0 { 1 } else { *w as u64 })
+	}
+
+	/// Claimable amount for a pool member holding `weight`, combining the yet-unsettled
+	/// delta on the accumulator with any already-settled `MemberRewards`.
+	fn earned(pool: &T::AccountId, member: &T::AccountId, weight: u64) -> BalanceOf<T> {
+		let stored: u128 = <Self as Store>::RewardPerWeightStored::get(pool).unique_saturated_into();
+		let paid: u128 = <Self as Store>::MemberRewardPerWeightPaid::get((pool, member)).unique_saturated_into();
+		let delta = stored.saturating_sub(paid);
+
+		let from_weight = (weight as u128).saturating_mul(delta) / REWARD_PER_WEIGHT_SCALE;
+		let pending: u128 = <Self as Store>::MemberRewards::get((pool, member)).unique_saturated_into();
+
+		BalanceOf::<T>::unique_saturated_from(from_weight.saturating_add(pending))
+	}
+
+	/// Settle a pool member's accrued rewards into `MemberRewards` and snapshot the
+	/// accumulator. Must be called with the member's weight *before* it changes, so that
+	/// rewards earned under the old weight aren't lost.
+	fn settle_member(pool: &T::AccountId, member: &T::AccountId, weight: u64) {
+		let earned = Self::earned(pool, member, weight);
+		<Self as Store>::MemberRewards::insert((pool, member), earned);
+		<Self as Store>::MemberRewardPerWeightPaid::insert(
+			(pool, member),
+			<Self as Store>::RewardPerWeightStored::get(pool),
+		);
+	}
+
 	fn do_reward_per_account(account: &T::AccountId, reward: BalanceOf<T>, when: T::BlockNumber) {
 		let account_reward_locks =
 			T::GenerateRewardLocks::generate_reward_locks(when, reward, LockParams::get());
@@ -423,24 +750,52 @@ impl<T: Config> Module<T> {
 
 		if account_reward_locks.len() > 0 {
 			let mut locks = Self::reward_locks(&account);
+			let existing_total: BalanceOf<T> = locks.values()
+				.fold(Zero::zero(), |acc: BalanceOf<T>, v| acc.saturating_add(*v));
+			let generated_total: BalanceOf<T> = account_reward_locks.values()
+				.fold(Zero::zero(), |acc: BalanceOf<T>, v| acc.saturating_add(*v));
+
+			// Below the dust threshold: leave the funds (already deposited above) immediately
+			// spendable instead of bloating the lock map. Gated on the whole generated lock
+			// set plus whatever's already locked plus `UnlockedDust` accrued from earlier
+			// skipped payouts, not per-entry and not this call's payout in isolation, so a
+			// high `divide` splitting an ordinary reward into many small buckets doesn't
+			// defeat the lock entirely. The lock is then kept only if the non-expired locked
+			// total reaches `MinLockAmount` (see `do_update_reward_locks`); `UnlockedDust`
+			// does not count towards that.
+			let pending_dust = <Self as Store>::UnlockedDust::get(&account);
+			if existing_total.saturating_add(generated_total).saturating_add(pending_dust) >= T::MinLockAmount::get() {
+				for (new_lock_number, new_lock_balance) in account_reward_locks {
+					let old_balance = *locks
+						.get(&new_lock_number)
+						.unwrap_or(&BalanceOf::<T>::default());
+					let new_balance = old_balance.saturating_add(new_lock_balance);
+					locks.insert(new_lock_number, new_balance);
+				}
 
-			for (new_lock_number, new_lock_balance) in account_reward_locks {
-				let old_balance = *locks
-					.get(&new_lock_number)
-					.unwrap_or(&BalanceOf::<T>::default());
-				let new_balance = old_balance.saturating_add(new_lock_balance);
-				locks.insert(new_lock_number, new_balance);
+				Self::do_update_reward_locks(&account, locks, when, false, true);
+				<Self as Store>::UnlockedDust::remove(&account);
+			} else {
+				<Self as Store>::UnlockedDust::insert(&account, pending_dust.saturating_add(generated_total));
 			}
-
-			Self::do_update_reward_locks(&account, locks, when, false);
 		}
 	}
 
+	/// Update `author`'s lock map to `locks`, expiring anything due by `current_number`.
+	///
+	/// `enforce_min_lock` gates the dust-drop: when the post-expiry `total_locked` is below
+	/// `MinLockAmount`, the *entire* remaining lock is dropped rather than left at a
+	/// negligible `set_lock`. That's correct for the reward/unlock paths, where the member
+	/// themself brought the total that low. It must stay `false` for a slash, where the
+	/// remainder is balance the miner hasn't paid down yet — dropping it there would
+	/// early-unlock the rest of a near-threshold miner's balance instead of just the slashed
+	/// fraction.
 	fn do_update_reward_locks(
 		author: &T::AccountId,
 		mut locks: BTreeMap<T::BlockNumber, BalanceOf<T>>,
 		current_number: T::BlockNumber,
 		force: bool,
+		enforce_min_lock: bool,
 	) {
 		let mut expired = Vec::new();
 
@@ -473,17 +828,77 @@ impl<T: Config> Module<T> {
 				locks.remove(&block_number);
 			}
 
-			<T as Config>::Currency::set_lock(
-				REWARDS_ID,
-				&author,
-				total_locked,
-				WithdrawReasons::except(WithdrawReasons::TRANSACTION_PAYMENT),
-			);
+			if enforce_min_lock && total_locked < T::MinLockAmount::get() {
+				// Dust: drop the lock outright rather than leaving it set for a
+				// negligible amount.
+				locks.clear();
+				<T as Config>::Currency::remove_lock(
+					REWARDS_ID,
+					&author,
+				);
+			} else {
+				<T as Config>::Currency::set_lock(
+					REWARDS_ID,
+					&author,
+					total_locked,
+					WithdrawReasons::except(WithdrawReasons::TRANSACTION_PAYMENT),
+				);
+			}
 		}
 
 		<Self as Store>::RewardLocks::insert(author, locks);
 	}
 
+	/// Slash `fraction` of `miner`'s remaining locked rewards, oldest entries first, and
+	/// redirect the slashed amount to `DonationDestination`. Returns the amount slashed.
+	fn slash_miner_locks(
+		miner: &T::AccountId,
+		fraction: Percent,
+		when: T::BlockNumber,
+	) -> BalanceOf<T> {
+		let mut locks = Self::reward_locks(miner);
+		let total_locked: BalanceOf<T> = locks.values()
+			.fold(Zero::zero(), |acc: BalanceOf<T>, v| acc.saturating_add(*v));
+
+		let nominal = fraction * total_locked;
+		if nominal.is_zero() {
+			return Zero::zero();
+		}
+
+		let (imbalance, remainder) = <T as Config>::Currency::slash(miner, nominal);
+		drop(imbalance);
+
+		// `remainder` is whatever `slash` couldn't actually take from the miner's balance.
+		// Free exactly that much locked bookkeeping and forward exactly that much to the
+		// donation destination — using the nominal `fraction * total_locked` for either
+		// would free more lock headroom, or mint more into the destination, than was
+		// actually removed from the miner.
+		let actually_slashed = nominal.saturating_sub(remainder);
+		if actually_slashed.is_zero() {
+			return Zero::zero();
+		}
+
+		let mut remaining = actually_slashed;
+		for locked_balance in locks.values_mut() {
+			if remaining.is_zero() {
+				break;
+			}
+			let taken = (*locked_balance).min(remaining);
+			*locked_balance = locked_balance.saturating_sub(taken);
+			remaining = remaining.saturating_sub(taken);
+		}
+		locks.retain(|_, locked_balance| !locked_balance.is_zero());
+
+		let donation_destination = T::DonationDestination::get();
+		drop(<T as Config>::Currency::deposit_creating(&donation_destination, actually_slashed));
+
+		// `enforce_min_lock: false` — a slash reducing the locked total below `MinLockAmount`
+		// must not early-unlock the rest of the miner's balance; see `do_update_reward_locks`.
+		Self::do_update_reward_locks(miner, locks, when, false, false);
+
+		actually_slashed
+	}
+
 	fn do_mints(mints: &BTreeMap<T::AccountId, BalanceOf<T>>) {
 		for (destination, mint) in mints {
 			drop(<T as Config>::Currency::deposit_creating(&destination, *mint));
@@ -491,6 +906,25 @@ impl<T: Config> Module<T> {
 	}
 }
 
+impl<T: Config> Module<T> {
+	/// Settle a pool member's pending rewards under their *old* weight, for callers that are
+	/// about to change a member's weight mid-accrual.
+	///
+	/// Call contract: this pallet does not own pool membership (`T::MiningPool` reads it from
+	/// wherever it's tracked) and so cannot call this itself on a weight change; the pallet
+	/// that *does* own membership (e.g. a mining-pool pallet) must call
+	/// `pallet_rewards::Module::<T>::on_member_weight_changed(pool, member, old_weight)`
+	/// synchronously, in the same extrinsic/block that mutates the member's weight, and
+	/// strictly *before* the mutation is applied — `old_weight` must be the weight as it
+	/// stood immediately prior to the change. Skipping this call for a weight change means
+	/// the member's next `claim_pool_rewards` mixes accrual from their old and new weight
+	/// instead of splitting it at the point of change. That caller is outside this crate
+	/// (no mining-pool pallet is part of this tree), so this hook has no in-tree caller yet.
+	pub fn on_member_weight_changed(pool: &T::AccountId, member: &T::AccountId, old_weight: u64) {
+		Self::settle_member(pool, member, old_weight);
+	}
+}
+
 impl<T: Config> RewardLocksApi<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	fn locks(account_id: &T::AccountId) -> BalanceOf<T> {
 		Self::reward_locks(account_id)